@@ -0,0 +1,92 @@
+use anyhow::{bail, Result};
+use ratatui::widgets::ListState;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// A named directory the watcher can jump straight to, skipping the
+/// `FileExplorer` walk.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Bookmark {
+    pub name: String,
+    pub path: PathBuf,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct BookmarkFile {
+    #[serde(default)]
+    bookmark: Vec<Bookmark>,
+}
+
+/// `~/.local/share/hm/bookmarks.toml`, honoring `$XDG_DATA_HOME` if set.
+pub fn path() -> Result<PathBuf> {
+    if let Ok(xdg) = std::env::var("XDG_DATA_HOME") {
+        return Ok(PathBuf::from(xdg).join("hm/bookmarks.toml"));
+    }
+    let Some(home) = homedir::my_home()? else {
+        bail!("failed to get home directory for the current user");
+    };
+    Ok(home.join(".local/share/hm/bookmarks.toml"))
+}
+
+/// Load saved bookmarks, defaulting to a single entry for the current
+/// project dir when nothing has been saved yet.
+pub fn load() -> Result<Vec<Bookmark>> {
+    let path = path()?;
+    let Ok(contents) = std::fs::read_to_string(&path) else {
+        return Ok(vec![Bookmark {
+            name: "current project".to_owned(),
+            path: std::env::current_dir()?,
+        }]);
+    };
+    let file: BookmarkFile = toml::from_str(&contents)?;
+    Ok(file.bookmark)
+}
+
+pub fn save(bookmarks: &[Bookmark]) -> Result<()> {
+    let path = path()?;
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let file = BookmarkFile {
+        bookmark: bookmarks.to_vec(),
+    };
+    std::fs::write(path, toml::to_string_pretty(&file)?)?;
+    Ok(())
+}
+
+/// `FileExplorer`-style selectable overlay listing saved bookmarks.
+pub struct BookmarkPicker {
+    bookmarks: Vec<Bookmark>,
+    state: ListState,
+}
+
+impl BookmarkPicker {
+    pub fn new(bookmarks: Vec<Bookmark>) -> Self {
+        let mut state = ListState::default();
+        state.select(Some(0));
+        Self { bookmarks, state }
+    }
+
+    pub fn bookmarks(&self) -> &[Bookmark] {
+        &self.bookmarks
+    }
+
+    pub fn state(&mut self) -> &mut ListState {
+        &mut self.state
+    }
+
+    pub fn select_prev(&mut self) {
+        let i = self.state.selected().unwrap_or(0).saturating_sub(1);
+        self.state.select(Some(i));
+    }
+
+    pub fn select_next(&mut self) {
+        let i =
+            (self.state.selected().unwrap_or(0) + 1).min(self.bookmarks.len().saturating_sub(1));
+        self.state.select(Some(i));
+    }
+
+    pub fn selected(&self) -> Option<&Bookmark> {
+        self.state.selected().and_then(|i| self.bookmarks.get(i))
+    }
+}
@@ -0,0 +1,106 @@
+use anyhow::{bail, Result};
+use serde::Deserialize;
+use std::path::PathBuf;
+
+/// Key characters for each overlay/action, remappable so `hm` doesn't fight a
+/// user's existing muscle memory. Most are pressed after the space chord
+/// leader; `history_prev`/`history_next` are pressed directly, and
+/// `bookmark_add` inside the file picker.
+#[derive(Debug, Deserialize, Clone, Copy)]
+#[serde(default)]
+pub struct Keys {
+    pub file_picker: char,
+    pub set_quality: char,
+    pub rerender: char,
+    pub move_render: char,
+    pub pick_scene: char,
+    pub process_list: char,
+    pub history_prev: char,
+    pub history_next: char,
+    pub toggle_source: char,
+    pub pick_bookmark: char,
+    pub bookmark_add: char,
+}
+
+impl Default for Keys {
+    fn default() -> Self {
+        Self {
+            file_picker: 'f',
+            set_quality: 'q',
+            rerender: 'r',
+            move_render: 'm',
+            pick_scene: 's',
+            process_list: 'p',
+            history_prev: '[',
+            history_next: ']',
+            toggle_source: 't',
+            pick_bookmark: 'b',
+            bookmark_add: 'a',
+        }
+    }
+}
+
+/// Everything that was previously hardcoded: default quality, which file
+/// extensions the watcher reacts to, the render/preview commands, the
+/// move-to destination, and the chord keymap. Loaded from
+/// `~/.config/hm/config.toml` (or `$XDG_CONFIG_HOME/hm/config.toml`), falling
+/// back to these defaults when the file doesn't exist.
+#[derive(Debug, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    pub default_quality: String,
+    pub watch_extensions: Vec<String>,
+    pub render_command: String,
+    pub render_args: Vec<String>,
+    pub preview_command: String,
+    pub move_to: Option<PathBuf>,
+    pub max_concurrent_renders: usize,
+    pub keys: Keys,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            default_quality: "l".to_owned(),
+            watch_extensions: vec!["py".to_owned()],
+            render_command: "manim".to_owned(),
+            render_args: Vec::new(),
+            preview_command: "mpv".to_owned(),
+            move_to: None,
+            max_concurrent_renders: 1,
+            keys: Keys::default(),
+        }
+    }
+}
+
+impl Config {
+    /// `~/.config/hm/config.toml`, honoring `$XDG_CONFIG_HOME` if set.
+    pub fn path() -> Result<PathBuf> {
+        if let Ok(xdg) = std::env::var("XDG_CONFIG_HOME") {
+            return Ok(PathBuf::from(xdg).join("hm/config.toml"));
+        }
+        let Some(home) = homedir::my_home()? else {
+            bail!("failed to get home directory for the current user");
+        };
+        Ok(home.join(".config/hm/config.toml"))
+    }
+
+    pub fn load() -> Result<Self> {
+        let path = Self::path()?;
+        let Ok(contents) = std::fs::read_to_string(&path) else {
+            return Ok(Self::default());
+        };
+        Ok(toml::from_str(&contents)?)
+    }
+
+    /// `move_to`, or `~/Videos` if unset.
+    pub fn move_to_dir(&self) -> Result<PathBuf> {
+        if let Some(dir) = &self.move_to {
+            return Ok(dir.clone());
+        }
+        let Some(home) = homedir::my_home()? else {
+            bail!("failed to get home directory for the current user");
+        };
+        Ok(home.join("Videos"))
+    }
+}
@@ -0,0 +1,93 @@
+use anyhow::Result;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use std::path::Path;
+use std::time::SystemTime;
+use syntect::easy::HighlightLines;
+use syntect::highlighting::{FontStyle, ThemeSet};
+use syntect::parsing::SyntaxSet;
+
+/// Syntax-highlighted view of the watched source file, re-highlighted only
+/// when its modified time changes and cached as ready-to-render `Line`s.
+pub struct SourcePane {
+    syntax_set: SyntaxSet,
+    theme_set: ThemeSet,
+    cached: Vec<Line<'static>>,
+    cached_mtime: Option<SystemTime>,
+    scroll: u16,
+}
+
+impl SourcePane {
+    pub fn new() -> Self {
+        Self {
+            syntax_set: SyntaxSet::load_defaults_newlines(),
+            theme_set: ThemeSet::load_defaults(),
+            cached: Vec::new(),
+            cached_mtime: None,
+            scroll: 0,
+        }
+    }
+
+    pub fn refresh_if_stale(&mut self, path: &Path) -> Result<()> {
+        let mtime = std::fs::metadata(path)?.modified()?;
+        if self.cached_mtime == Some(mtime) {
+            return Ok(());
+        }
+
+        let source = std::fs::read_to_string(path)?;
+        let syntax = self
+            .syntax_set
+            .find_syntax_by_extension("py")
+            .unwrap_or_else(|| self.syntax_set.find_syntax_plain_text());
+        let theme = &self.theme_set.themes["base16-ocean.dark"];
+        let mut highlighter = HighlightLines::new(syntax, theme);
+
+        self.cached = source
+            .lines()
+            .map(|line| {
+                let ranges = highlighter
+                    .highlight_line(line, &self.syntax_set)
+                    .unwrap_or_default();
+                Line::from(
+                    ranges
+                        .into_iter()
+                        .map(|(style, text)| {
+                            let fg = style.foreground;
+                            let mut modifier = Modifier::empty();
+                            if style.font_style.contains(FontStyle::BOLD) {
+                                modifier |= Modifier::BOLD;
+                            }
+                            if style.font_style.contains(FontStyle::ITALIC) {
+                                modifier |= Modifier::ITALIC;
+                            }
+                            Span::styled(
+                                text.to_string(),
+                                Style::default()
+                                    .fg(Color::Rgb(fg.r, fg.g, fg.b))
+                                    .add_modifier(modifier),
+                            )
+                        })
+                        .collect::<Vec<_>>(),
+                )
+            })
+            .collect();
+        self.cached_mtime = Some(mtime);
+        Ok(())
+    }
+
+    pub fn lines(&self) -> &[Line<'static>] {
+        &self.cached
+    }
+
+    pub fn scroll(&self) -> u16 {
+        self.scroll
+    }
+
+    pub fn scroll_up(&mut self) {
+        self.scroll = self.scroll.saturating_sub(1);
+    }
+
+    pub fn scroll_down(&mut self) {
+        self.scroll = self.scroll.saturating_add(1);
+    }
+}
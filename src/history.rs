@@ -0,0 +1,77 @@
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::Instant;
+
+use tui_term::vt100;
+
+/// One manim invocation: its own scrollback so a prior failure or success stays
+/// around after a newer render starts writing somewhere else.
+pub struct Entry {
+    pub command_line: String,
+    pub source_file: String,
+    pub quality_symbol: &'static str,
+    pub started_at: Instant,
+    pub exit_status: Arc<Mutex<Option<i32>>>,
+    pub parser: Arc<RwLock<vt100::Parser>>,
+}
+
+impl Entry {
+    pub fn new(
+        command_line: String,
+        source_file: String,
+        quality_symbol: &'static str,
+        rows: u16,
+        cols: u16,
+    ) -> Self {
+        Self {
+            command_line,
+            source_file,
+            quality_symbol,
+            started_at: Instant::now(),
+            exit_status: Arc::new(Mutex::new(None)),
+            parser: Arc::new(RwLock::new(vt100::Parser::new(rows, cols, 0))),
+        }
+    }
+}
+
+/// All renders that have been kicked off this session, with a cursor over
+/// which one `main_ui` is currently displaying.
+#[derive(Default)]
+pub struct History {
+    entries: Vec<Entry>,
+    selected: usize,
+}
+
+impl History {
+    /// Push a new run, following it with the cursor only if the cursor was
+    /// already on the newest entry — otherwise a background re-render would
+    /// yank the user off an older entry they navigated back to compare.
+    pub fn push(&mut self, entry: Entry) {
+        let was_at_tail = self.selected + 1 >= self.entries.len();
+        self.entries.push(entry);
+        if was_at_tail {
+            self.selected = self.entries.len() - 1;
+        }
+    }
+
+    pub fn select_prev(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    pub fn select_next(&mut self) {
+        if self.selected + 1 < self.entries.len() {
+            self.selected += 1;
+        }
+    }
+
+    pub fn current(&self) -> Option<&Entry> {
+        self.entries.get(self.selected)
+    }
+
+    pub fn entries(&self) -> &[Entry] {
+        &self.entries
+    }
+
+    pub fn selected_index(&self) -> usize {
+        self.selected
+    }
+}
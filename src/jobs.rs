@@ -0,0 +1,164 @@
+use std::time::Instant;
+
+use portable_pty::ChildKiller;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JobState {
+    Queued,
+    Running,
+    Done { exit_code: i32 },
+    Failed,
+}
+
+/// One manim invocation tracked end-to-end: what it renders, whether it's
+/// waiting for a concurrency slot or already spawned, and (once running) a
+/// handle that can kill the underlying PTY child without owning it.
+pub struct Job {
+    pub id: u64,
+    pub source_file: String,
+    pub scene: Option<String>,
+    pub quality_symbol: &'static str,
+    pub state: JobState,
+    pub submitted_at: Instant,
+    killer: Option<Box<dyn ChildKiller + Send + Sync>>,
+}
+
+impl Job {
+    pub fn elapsed(&self) -> std::time::Duration {
+        self.submitted_at.elapsed()
+    }
+}
+
+/// How many finished (`Done`/`Failed`) jobs to keep around for the
+/// process-list overlay before pruning the oldest ones, so a long session
+/// doesn't grow the job list without bound.
+const MAX_FINISHED: usize = 20;
+
+/// Serializes renders to at most `max_concurrent` at once, queueing the rest
+/// and coalescing duplicate pending requests for the same file+scene. This is
+/// the backpressure that replaces the old same-file time debounce.
+pub struct JobQueue {
+    jobs: Vec<Job>,
+    next_id: u64,
+    max_concurrent: usize,
+}
+
+impl JobQueue {
+    pub fn new(max_concurrent: usize) -> Self {
+        Self {
+            jobs: Vec::new(),
+            next_id: 0,
+            max_concurrent: max_concurrent.max(1),
+        }
+    }
+
+    /// Enqueue a render, unless an equivalent job for the same file+scene is
+    /// already queued or running. Returns the new job's id, if one was created.
+    pub fn submit(
+        &mut self,
+        source_file: String,
+        scene: Option<String>,
+        quality_symbol: &'static str,
+    ) -> Option<u64> {
+        let duplicate = self.jobs.iter().any(|j| {
+            matches!(j.state, JobState::Queued | JobState::Running)
+                && j.source_file == source_file
+                && j.scene == scene
+        });
+        if duplicate {
+            return None;
+        }
+        let id = self.next_id;
+        self.next_id += 1;
+        self.jobs.push(Job {
+            id,
+            source_file,
+            scene,
+            quality_symbol,
+            state: JobState::Queued,
+            submitted_at: Instant::now(),
+            killer: None,
+        });
+        Some(id)
+    }
+
+    fn running_count(&self) -> usize {
+        self.jobs
+            .iter()
+            .filter(|j| j.state == JobState::Running)
+            .count()
+    }
+
+    /// If there's a free concurrency slot, return the next queued job's id so
+    /// the caller can spawn it and report back with `mark_running`.
+    pub fn next_to_run(&self) -> Option<u64> {
+        if self.running_count() >= self.max_concurrent {
+            return None;
+        }
+        self.jobs
+            .iter()
+            .find(|j| j.state == JobState::Queued)
+            .map(|j| j.id)
+    }
+
+    pub fn mark_running(&mut self, id: u64, killer: Box<dyn ChildKiller + Send + Sync>) {
+        if let Some(job) = self.jobs.iter_mut().find(|j| j.id == id) {
+            job.state = JobState::Running;
+            job.killer = Some(killer);
+        }
+    }
+
+    pub fn mark_done(&mut self, id: u64, exit_code: i32) {
+        if let Some(job) = self.jobs.iter_mut().find(|j| j.id == id) {
+            job.state = JobState::Done { exit_code };
+            job.killer = None;
+        }
+        self.prune_finished();
+    }
+
+    pub fn mark_failed(&mut self, id: u64) {
+        if let Some(job) = self.jobs.iter_mut().find(|j| j.id == id) {
+            job.state = JobState::Failed;
+            job.killer = None;
+        }
+        self.prune_finished();
+    }
+
+    /// Drop the oldest finished jobs past `MAX_FINISHED`, keeping queued and
+    /// running jobs untouched.
+    fn prune_finished(&mut self) {
+        let finished = self
+            .jobs
+            .iter()
+            .filter(|j| matches!(j.state, JobState::Done { .. } | JobState::Failed))
+            .count();
+        let mut to_drop = finished.saturating_sub(MAX_FINISHED);
+        if to_drop == 0 {
+            return;
+        }
+        self.jobs.retain(|j| {
+            if to_drop > 0 && matches!(j.state, JobState::Done { .. } | JobState::Failed) {
+                to_drop -= 1;
+                false
+            } else {
+                true
+            }
+        });
+    }
+
+    pub fn kill(&mut self, id: u64) {
+        if let Some(job) = self.jobs.iter_mut().find(|j| j.id == id) {
+            if let Some(killer) = &job.killer {
+                let _ = killer.kill();
+            }
+        }
+    }
+
+    pub fn get(&self, id: u64) -> Option<&Job> {
+        self.jobs.iter().find(|j| j.id == id)
+    }
+
+    pub fn jobs(&self) -> &[Job] {
+        &self.jobs
+    }
+}
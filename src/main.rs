@@ -8,7 +8,7 @@ use std::sync::Mutex;
 use std::time::{Duration, Instant};
 use std::{
     io::{self, BufWriter},
-    sync::{Arc, RwLock},
+    sync::Arc,
 };
 
 use crossterm::{
@@ -17,7 +17,7 @@ use crossterm::{
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
 use notify::{EventHandler, RecursiveMode, Watcher};
-use portable_pty::{CommandBuilder, NativePtySystem, PtySize, PtySystem};
+use portable_pty::{Child, CommandBuilder, NativePtySystem, PtySize, PtySystem};
 use ratatui::{
     backend::{Backend, CrosstermBackend},
     style::{Color, Style, Stylize},
@@ -25,11 +25,24 @@ use ratatui::{
     widgets::{Block, Borders, Paragraph},
     Frame, Terminal,
 };
-use tui_term::vt100;
 use tui_term::vt100::Screen;
 use tui_term::widget::PseudoTerminal;
+pub mod bookmarks;
+pub mod config;
+pub mod highlight;
+pub mod history;
+pub mod jobs;
 pub mod legend;
+pub mod preview;
+pub mod scenes;
+use bookmarks::{Bookmark, BookmarkPicker};
+use config::Config;
+use highlight::SourcePane;
+use history::{Entry, History};
+use jobs::JobQueue;
 use legend::LegendIterator;
+use preview::{GraphicsProtocol, PreviewCache};
+use scenes::ScenePicker;
 
 #[derive(Debug, Clone)]
 struct Size {
@@ -43,6 +56,10 @@ enum Chord {
     ReRender,
     Move,
     SetQuality,
+    PickScene,
+    ProcessList,
+    ToggleSource,
+    PickBookmark,
 }
 
 #[derive(Debug, Default)]
@@ -78,6 +95,16 @@ impl Quality {
             Quality::K => "k",
         }
     }
+
+    fn from_symbol(symbol: &str) -> Self {
+        match symbol {
+            "m" => Quality::M,
+            "h" => Quality::H,
+            "p" => Quality::P,
+            "k" => Quality::K,
+            _ => Quality::L,
+        }
+    }
 }
 
 struct Model {
@@ -89,8 +116,19 @@ struct Model {
     file_picker: Option<FileExplorer>,
     last_file: String,
     rendered_to: Arc<Mutex<Option<String>>>,
-    last_time: Instant,
     size: Size,
+    graphics_protocol: GraphicsProtocol,
+    preview_area: Option<ratatui::layout::Rect>,
+    preview_cache: PreviewCache,
+    history: History,
+    scene_picker: Option<ScenePicker>,
+    selected_scene: Option<String>,
+    jobs: Arc<Mutex<JobQueue>>,
+    process_list: Option<usize>,
+    config: Config,
+    source_pane: Option<SourcePane>,
+    bookmarks: Vec<Bookmark>,
+    bookmark_picker: Option<BookmarkPicker>,
 }
 
 impl Model {
@@ -102,15 +140,45 @@ impl Model {
     }
 
     fn ingest_chord(&mut self, keycode: KeyCode) {
+        let keys = self.config.keys;
         self.chord = match keycode {
-            KeyCode::Char('f') => {
+            KeyCode::Char(c) if c == keys.file_picker => {
                 let fp = FileExplorer::new().unwrap();
                 self.file_picker = Some(fp);
                 Some(Chord::FilePicker)
             }
-            KeyCode::Char('q') => Some(Chord::SetQuality),
-            KeyCode::Char('r') => Some(Chord::ReRender),
-            KeyCode::Char('m') => Some(Chord::Move),
+            KeyCode::Char(c) if c == keys.set_quality => Some(Chord::SetQuality),
+            KeyCode::Char(c) if c == keys.rerender => Some(Chord::ReRender),
+            KeyCode::Char(c) if c == keys.move_render => Some(Chord::Move),
+            KeyCode::Char(c) if c == keys.process_list => {
+                self.process_list = Some(0);
+                Some(Chord::ProcessList)
+            }
+            KeyCode::Char(c) if c == keys.toggle_source => {
+                self.source_pane = if self.source_pane.is_some() {
+                    None
+                } else {
+                    let mut pane = SourcePane::new();
+                    let _ = pane.refresh_if_stale(std::path::Path::new(&self.last_file));
+                    Some(pane)
+                };
+                Some(Chord::ToggleSource)
+            }
+            KeyCode::Char(c) if c == keys.pick_bookmark => {
+                self.bookmark_picker = Some(BookmarkPicker::new(self.bookmarks.clone()));
+                Some(Chord::PickBookmark)
+            }
+            KeyCode::Char(c) if c == keys.pick_scene => {
+                let scenes = scenes::parse_scenes(
+                    &std::fs::read_to_string(&self.last_file).unwrap_or_default(),
+                );
+                if scenes.is_empty() {
+                    None
+                } else {
+                    self.scene_picker = Some(ScenePicker::new(scenes));
+                    Some(Chord::PickScene)
+                }
+            }
             _ => None,
         };
     }
@@ -125,22 +193,64 @@ impl Model {
         };
     }
 
-    fn render(&mut self, parser: Arc<RwLock<vt100::Parser>>, name: &str) -> Result<()> {
-        let mut cmd = CommandBuilder::new("manim");
+    /// Enqueue a render for `name`, deduplicated against any already
+    /// queued/running job for the same file+scene. Actual spawning happens in
+    /// `pump_queue` once a concurrency slot is free.
+    fn submit_render(&mut self, name: &str) {
+        self.jobs.lock().unwrap().submit(
+            name.to_owned(),
+            self.selected_scene.clone(),
+            self.quality.symbol(),
+        );
+    }
 
+    /// Spawn as many queued jobs as the concurrency limit allows.
+    fn pump_queue(&mut self) -> Result<()> {
+        loop {
+            let next = self.jobs.lock().unwrap().next_to_run();
+            let Some(id) = next else {
+                break;
+            };
+            self.spawn_job(id)?;
+        }
+        Ok(())
+    }
+
+    fn spawn_job(&mut self, id: u64) -> Result<()> {
+        let Some((source_file, scene, quality_symbol)) = self.jobs.lock().unwrap().get(id).map(
+            |j| (j.source_file.clone(), j.scene.clone(), j.quality_symbol),
+        ) else {
+            return Ok(());
+        };
+
+        let mut cmd = CommandBuilder::new(&self.config.render_command);
         cmd.args([
             "render",
             "--preview",
             "--preview_command",
-            "mpv",
+            &self.config.preview_command,
             "--quality",
-            self.quality.symbol(),
-            name,
+            quality_symbol,
         ]);
+        cmd.args(&self.config.render_args);
+        cmd.arg(&source_file);
+        if let Some(scene) = &scene {
+            cmd.arg(scene);
+        }
         cmd.cwd(&self.directory);
-        self.run_command(parser, cmd)
+
+        let mut command_line = format!(
+            "{} render --quality {quality_symbol} {source_file}",
+            self.config.render_command
+        );
+        if let Some(scene) = &scene {
+            command_line.push(' ');
+            command_line.push_str(scene);
+        }
+
+        self.run_command(cmd, command_line, source_file, quality_symbol, id)
     }
-    fn move_to_workdir(&mut self, _parser: Arc<RwLock<vt100::Parser>>) -> Result<()> {
+    fn move_to_workdir(&mut self) -> Result<()> {
         let Some(name) = ({
             // jesus rust is fucking high
             let Ok(mut name_handle) = self.rendered_to.lock() else {
@@ -151,19 +261,17 @@ impl Model {
             return Ok(());
         };
 
-        let Some(home) = homedir::my_home()? else {
-            bail!("failed to get home directory for the current user");
-        };
-
-        let videos = home.join("Videos");
-        std::fs::rename(name, videos)?;
+        std::fs::rename(name, self.config.move_to_dir()?)?;
         Ok(())
     }
 
     fn run_command(
         &mut self,
-        parser: Arc<RwLock<vt100::Parser>>,
         cmd: CommandBuilder,
+        command_line: String,
+        source_file: String,
+        quality_symbol: &'static str,
+        job_id: u64,
     ) -> Result<()> {
         let Size { rows, cols } = self.pty_size();
 
@@ -173,11 +281,19 @@ impl Model {
             ..Default::default()
         })?;
 
+        let entry = Entry::new(command_line, source_file, quality_symbol, rows, cols);
+        let parser = entry.parser.clone();
+        let exit_status = entry.exit_status.clone();
+        self.history.push(entry);
+
         {
-            let parser = parser.clone();
             let mut child = pair.slave.spawn_command(cmd)?;
+            let killer = child.clone_killer();
+            self.jobs.lock().unwrap().mark_running(job_id, killer);
+
             let mut reader = pair.master.try_clone_reader()?;
             let filename = self.rendered_to.clone();
+            let jobs = self.jobs.clone();
             std::thread::spawn(move || {
                 drop(pair.slave);
 
@@ -188,32 +304,39 @@ impl Model {
                     match reader.read(&mut buf).unwrap() {
                         0 => break,
                         size => {
-                            let buf_string = String::from_utf8_lossy(&buf);
-                            let Some(start) = buf_string.find("media") else {
-                                continue;
-                            };
-                            let haystack = &buf_string[start..];
-                            let end = haystack
-                                .find(".mp4")
-                                .or(haystack.find(".mov"))
-                                .or(haystack.find(".png"));
-                            let Some(end) = end else {
-                                continue;
-                            };
-                            let rendered_filename = haystack[..end + 4].to_owned();
-                            filename.lock().unwrap().replace(rendered_filename);
+                            // Always feed the parser so progress output and
+                            // tracebacks stay in scrollback, even when this
+                            // chunk carries no rendered-file path.
+                            parser.write().unwrap().process(&buf[..size]);
 
-                            parser.write().unwrap().process(&buf[..size])
+                            let buf_string = String::from_utf8_lossy(&buf[..size]);
+                            if let Some(start) = buf_string.find("media") {
+                                let haystack = &buf_string[start..];
+                                let end = haystack
+                                    .find(".mp4")
+                                    .or(haystack.find(".mov"))
+                                    .or(haystack.find(".png"));
+                                if let Some(end) = end {
+                                    let rendered_filename = haystack[..end + 4].to_owned();
+                                    filename.lock().unwrap().replace(rendered_filename);
+                                }
+                            }
                         }
                     }
                 }
                 // Drop writer on purpose
                 pair.master.take_writer().unwrap();
 
-                if let Ok(exit) = child.wait() {
-                    parser.write().unwrap().process(
-                        format!("\r\nmanim exited with code: {}\r\n", exit.exit_code()).as_bytes(),
-                    );
+                match child.wait() {
+                    Ok(exit) => {
+                        let code = exit.exit_code() as i32;
+                        exit_status.lock().unwrap().replace(code);
+                        jobs.lock().unwrap().mark_done(job_id, code);
+                        parser.write().unwrap().process(
+                            format!("\r\nmanim exited with code: {code}\r\n").as_bytes(),
+                        );
+                    }
+                    Err(_) => jobs.lock().unwrap().mark_failed(job_id),
                 }
 
                 drop(pair.master);
@@ -224,19 +347,31 @@ impl Model {
 }
 
 fn main() -> Result<()> {
-    let (mut terminal, size) = setup_terminal()?;
+    let (mut terminal, size, graphics_protocol) = setup_terminal()?;
+    let config = Config::load()?;
 
     let mut model = Model {
         directory: std::env::current_dir()?,
-        last_time: Instant::now(),
         prev_was_space: None,
         chord: None,
         rendered_to: Arc::new(Mutex::new(None)),
-        quality: Quality::L,
+        quality: Quality::from_symbol(&config.default_quality),
         pty_system: UnixPtySystem::default(),
         file_picker: None,
         last_file: String::default(),
         size,
+        graphics_protocol,
+        preview_area: None,
+        preview_cache: PreviewCache::new(),
+        history: History::default(),
+        scene_picker: None,
+        selected_scene: None,
+        jobs: Arc::new(Mutex::new(JobQueue::new(config.max_concurrent_renders))),
+        process_list: None,
+        source_pane: None,
+        bookmarks: bookmarks::load().unwrap_or_default(),
+        bookmark_picker: None,
+        config,
     };
 
     run(&mut terminal, &mut model)?;
@@ -247,6 +382,7 @@ fn main() -> Result<()> {
 
 pub struct EventSender {
     tx: Sender<String>,
+    watch_extensions: Vec<String>,
 }
 
 impl EventHandler for EventSender {
@@ -254,11 +390,11 @@ impl EventHandler for EventSender {
         match event {
             Ok(event) => {
                 if let notify::EventKind::Modify(notify::event::ModifyKind::Data(_)) = event.kind {
-                    for path in event
-                        .paths
-                        .into_iter()
-                        .filter(|p| p.extension().is_some_and(|ext| ext == "py"))
-                    {
+                    for path in event.paths.into_iter().filter(|p| {
+                        p.extension().is_some_and(|ext| {
+                            self.watch_extensions.iter().any(|e| e == &*ext.to_string_lossy())
+                        })
+                    }) {
                         // re-render them
                         self.tx
                             .send(path.to_string_lossy().to_string())
@@ -271,12 +407,12 @@ impl EventHandler for EventSender {
     }
 }
 
-fn run<B: Backend>(terminal: &mut Terminal<B>, model: &mut Model) -> Result<()> {
-    let Size { cols, rows } = model.pty_size();
-    let parser = Arc::new(RwLock::new(vt100::Parser::new(rows, cols, 0)));
-
+fn run<B: Backend + io::Write>(terminal: &mut Terminal<B>, model: &mut Model) -> Result<()> {
     let (tx, rx) = channel();
-    let es = EventSender { tx };
+    let es = EventSender {
+        tx,
+        watch_extensions: model.config.watch_extensions.clone(),
+    };
     // Automatically select the best implementation for your platform.
     let mut watcher = notify::recommended_watcher(es)?;
 
@@ -287,7 +423,22 @@ fn run<B: Backend>(terminal: &mut Terminal<B>, model: &mut Model) -> Result<()>
     let mut last_tick = Instant::now();
 
     loop {
-        terminal.draw(|f| ui(f, parser.read().unwrap().screen(), model))?;
+        if let Some(rendered_to) = model.rendered_to.lock().unwrap().clone() {
+            let (cols, rows) = model
+                .preview_area
+                .map(|a| (a.width, a.height))
+                .unwrap_or((model.size.cols, model.size.rows));
+            model
+                .preview_cache
+                .refresh(&rendered_to, model.graphics_protocol, cols, rows);
+        }
+        let current_parser = model.history.current().map(|e| e.parser.clone());
+        terminal.draw(|f| {
+            let guard = current_parser.as_ref().map(|p| p.read().unwrap());
+            let screen = guard.as_ref().map(|g| g.screen());
+            ui(f, screen, model)
+        })?;
+        draw_preview(terminal, model)?;
         let t_size = terminal.get_frame().size();
         model.size = Size {
             cols: t_size.width,
@@ -295,17 +446,118 @@ fn run<B: Backend>(terminal: &mut Terminal<B>, model: &mut Model) -> Result<()>
         };
         let timeout = tick_rate.saturating_sub(last_tick.elapsed());
         if let Ok(name) = rx.try_recv() {
-            if model.last_file == name && model.last_time.elapsed() < Duration::from_millis(500) {
-                continue;
+            let scenes = scenes::parse_scenes(&std::fs::read_to_string(&name).unwrap_or_default());
+            if !model
+                .selected_scene
+                .as_ref()
+                .is_some_and(|s| scenes.contains(s))
+            {
+                model.selected_scene = None;
+            }
+            match scenes.len() {
+                0 => {}
+                1 => model.selected_scene = Some(scenes[0].clone()),
+                _ if model.selected_scene.is_none() => {
+                    model.scene_picker = Some(ScenePicker::new(scenes));
+                }
+                _ => {}
+            }
+            model.last_file = name.clone();
+            if let Some(pane) = model.source_pane.as_mut() {
+                pane.refresh_if_stale(std::path::Path::new(&name))?;
+            }
+            if model.scene_picker.is_none() {
+                model.submit_render(&name);
             }
-            model.render(parser.clone(), &name)?;
-            model.last_file = name;
-            model.last_time = Instant::now();
         }
+        model.pump_queue()?;
 
         if event::poll(timeout)? {
             let read_event = event::read()?;
 
+            // Send keystrokes to the process-list overlay if one is open
+            if let Some(selected) = model.process_list {
+                if let Event::Key(key) = read_event {
+                    if key.kind == KeyEventKind::Press {
+                        let job_count = model.jobs.lock().unwrap().jobs().len();
+                        match key.code {
+                            KeyCode::Char('q') | KeyCode::Esc => model.process_list = None,
+                            KeyCode::Up | KeyCode::Char('k') => {
+                                model.process_list = Some(selected.saturating_sub(1));
+                            }
+                            KeyCode::Down | KeyCode::Char('j') => {
+                                model.process_list =
+                                    Some((selected + 1).min(job_count.saturating_sub(1)));
+                            }
+                            KeyCode::Char('x') => {
+                                let id = model
+                                    .jobs
+                                    .lock()
+                                    .unwrap()
+                                    .jobs()
+                                    .get(selected)
+                                    .map(|j| j.id);
+                                if let Some(id) = id {
+                                    model.jobs.lock().unwrap().kill(id);
+                                }
+                            }
+                            _ => (),
+                        }
+                    }
+                }
+                continue;
+            }
+
+            // Send keystrokes to the scene picker if one is open
+            if model.scene_picker.is_some() {
+                if let Event::Key(key) = read_event {
+                    if key.kind == KeyEventKind::Press {
+                        match key.code {
+                            KeyCode::Char('q') => return Ok(()),
+                            KeyCode::Up | KeyCode::Char('k') => {
+                                model.scene_picker.as_mut().unwrap().select_prev()
+                            }
+                            KeyCode::Down | KeyCode::Char('j') => {
+                                model.scene_picker.as_mut().unwrap().select_next()
+                            }
+                            KeyCode::Char(' ') | KeyCode::Enter => {
+                                let picker = model.scene_picker.take().unwrap();
+                                model.selected_scene = picker.selected().map(str::to_owned);
+                                let name = model.last_file.clone();
+                                model.submit_render(&name);
+                            }
+                            KeyCode::Esc => model.scene_picker = None,
+                            _ => (),
+                        }
+                    }
+                }
+                continue;
+            }
+
+            // Send keystrokes to the bookmark picker if one is open
+            if let Some(picker) = model.bookmark_picker.as_mut() {
+                if let Event::Key(key) = read_event {
+                    if key.kind == KeyEventKind::Press {
+                        match key.code {
+                            KeyCode::Char('q') => return Ok(()),
+                            KeyCode::Up | KeyCode::Char('k') => picker.select_prev(),
+                            KeyCode::Down | KeyCode::Char('j') => picker.select_next(),
+                            KeyCode::Char(' ') | KeyCode::Enter => {
+                                if let Some(bookmark) = picker.selected() {
+                                    watcher.unwatch(&model.directory)?;
+                                    model.directory = bookmark.path.clone();
+                                    watcher.watch(&model.directory, RecursiveMode::Recursive)?;
+                                }
+                                model.bookmark_picker = None;
+                            }
+                            KeyCode::Esc => model.bookmark_picker = None,
+                            _ => (),
+                        }
+                    }
+                }
+                continue;
+            }
+
             // Send keystrokes to file picker if we are in a file picker view
             if let Some(picker) = model.file_picker.as_mut() {
                 if let Event::Key(key) = read_event {
@@ -319,6 +571,18 @@ fn run<B: Backend>(terminal: &mut Terminal<B>, model: &mut Model) -> Result<()>
                                 model.file_picker = None;
                                 continue;
                             }
+                            KeyCode::Char(c) if c == model.config.keys.bookmark_add => {
+                                let path = picker.cwd().to_owned();
+                                if !model.bookmarks.iter().any(|b| b.path == path) {
+                                    let name = path
+                                        .file_name()
+                                        .map(|n| n.to_string_lossy().into_owned())
+                                        .unwrap_or_else(|| path.to_string_lossy().into_owned());
+                                    model.bookmarks.push(Bookmark { name, path });
+                                    let _ = bookmarks::save(&model.bookmarks);
+                                }
+                                continue;
+                            }
                             KeyCode::Esc => {
                                 model.file_picker = None;
                                 continue;
@@ -342,6 +606,22 @@ fn run<B: Backend>(terminal: &mut Terminal<B>, model: &mut Model) -> Result<()>
                         match key.code {
                             KeyCode::Char('q') => return Ok(()),
                             KeyCode::Char(' ') => model.prev_was_space = Some(()),
+                            KeyCode::Char(c) if c == model.config.keys.history_prev => {
+                                model.history.select_prev()
+                            }
+                            KeyCode::Char(c) if c == model.config.keys.history_next => {
+                                model.history.select_next()
+                            }
+                            KeyCode::Up => {
+                                if let Some(pane) = model.source_pane.as_mut() {
+                                    pane.scroll_up();
+                                }
+                            }
+                            KeyCode::Down => {
+                                if let Some(pane) = model.source_pane.as_mut() {
+                                    pane.scroll_down();
+                                }
+                            }
                             _ => (),
                         }
                     }
@@ -354,8 +634,8 @@ fn run<B: Backend>(terminal: &mut Terminal<B>, model: &mut Model) -> Result<()>
             }
             // Run the rendering command
             match model.chord {
-                Some(Chord::ReRender) => model.render(parser.clone(), &name.clone())?,
-                Some(Chord::Move) => model.move_to_workdir(parser.clone())?,
+                Some(Chord::ReRender) => model.submit_render(&name.clone()),
+                Some(Chord::Move) => model.move_to_workdir()?,
                 _ => (),
             }
         }
@@ -365,6 +645,38 @@ fn run<B: Backend>(terminal: &mut Terminal<B>, model: &mut Model) -> Result<()>
     }
 }
 
+/// For terminals that speak Kitty graphics or sixel, blit the cached preview
+/// payload into `model.preview_area` with raw escape sequences, bypassing
+/// ratatui's cell buffer (which can't carry pixel data). The payload itself
+/// is decoded/encoded once per render in `model.preview_cache`, not here.
+/// Terminals with neither protocol get a half-block rendering drawn as
+/// ordinary cells inside `main_ui` instead, so there's nothing to do here.
+/// Only actually writes when the cache reports a new frame, so the unchanged
+/// common case doesn't re-blit (and for Kitty, re-register) the same image on
+/// every tick.
+fn draw_preview<B: Backend + io::Write>(
+    terminal: &mut Terminal<B>,
+    model: &mut Model,
+) -> Result<()> {
+    if model.graphics_protocol == GraphicsProtocol::None {
+        return Ok(());
+    }
+    let Some(area) = model.preview_area else {
+        return Ok(());
+    };
+    if !model.preview_cache.take_dirty() {
+        return Ok(());
+    }
+    let Some(payload) = model.preview_cache.payload() else {
+        return Ok(());
+    };
+
+    let escapes = format!("\x1b[{};{}H{}", area.y + 1, area.x + 1, payload);
+    write!(terminal.backend_mut(), "{escapes}")?;
+    terminal.backend_mut().flush()?;
+    Ok(())
+}
+
 fn truncate_string_by(s: &str, by: usize) -> String {
     if by == 0 {
         return s.to_string();
@@ -383,8 +695,8 @@ fn truncate_string_to(s: &str, to: usize) -> String {
     truncate_string_by(s, by)
 }
 
-fn main_ui(f: &mut Frame, screen: &Screen, model: &mut Model) {
-    let chunks = ratatui::layout::Layout::default()
+fn main_ui(f: &mut Frame, screen: Option<&Screen>, model: &mut Model) {
+    let outer = ratatui::layout::Layout::default()
         .direction(ratatui::layout::Direction::Vertical)
         .margin(1)
         .constraints([
@@ -393,10 +705,69 @@ fn main_ui(f: &mut Frame, screen: &Screen, model: &mut Model) {
             Constraint::Min(1),
         ])
         .split(f.size());
-    let title = Line::from(" manim output ");
-    let block = Block::default().borders(Borders::ALL).title(title);
-    let pseudo_term = PseudoTerminal::new(screen).block(block);
-    f.render_widget(pseudo_term, chunks[1]);
+
+    let has_preview = model.rendered_to.lock().unwrap().is_some();
+    let has_source = model.source_pane.is_some();
+    let mut body_constraints = vec![Constraint::Length(28), Constraint::Min(20)];
+    if has_source {
+        body_constraints.push(Constraint::Percentage(35));
+    }
+    if has_preview {
+        body_constraints.push(Constraint::Percentage(35));
+    }
+    let body = ratatui::layout::Layout::default()
+        .direction(ratatui::layout::Direction::Horizontal)
+        .constraints(body_constraints)
+        .split(outer[1]);
+
+    render_history_list(f, body[0], model);
+
+    let title = model
+        .history
+        .current()
+        .map(|entry| {
+            format!(
+                " {} ",
+                truncate_string_to(&entry.command_line, (body[1].width as usize).max(8) - 2)
+            )
+        })
+        .unwrap_or_else(|| " manim output ".to_string());
+    let block = Block::default().borders(Borders::ALL).title(Line::from(title));
+    match screen {
+        Some(screen) => f.render_widget(PseudoTerminal::new(screen).block(block), body[1]),
+        None => f.render_widget(
+            Paragraph::new("no renders yet").block(block),
+            body[1],
+        ),
+    }
+
+    let chunks = outer;
+    let mut next_col = 2;
+    if let Some(pane) = model.source_pane.as_ref() {
+        let area = body[next_col];
+        next_col += 1;
+        let source_block = Block::default().borders(Borders::ALL).title(" source ");
+        let inner = source_block.inner(area);
+        f.render_widget(source_block, area);
+        let paragraph = Paragraph::new(pane.lines().to_vec()).scroll((pane.scroll(), 0));
+        f.render_widget(paragraph, inner);
+    }
+    if has_preview {
+        let area = body[next_col];
+        let preview_block = Block::default().borders(Borders::ALL).title(" preview ");
+        let inner = preview_block.inner(area);
+        f.render_widget(preview_block, area);
+        model.preview_area = Some(inner);
+
+        if model.graphics_protocol == GraphicsProtocol::None {
+            if let Some(frame) = model.preview_cache.frame() {
+                let lines = preview::render_halfblocks(frame, inner.width, inner.height);
+                f.render_widget(Paragraph::new(lines), inner);
+            }
+        }
+    } else {
+        model.preview_area = None;
+    }
 
     let dir = model.directory.to_string_lossy().into_owned();
     let status_line_len = format!(
@@ -422,6 +793,19 @@ fn main_ui(f: &mut Frame, screen: &Screen, model: &mut Model) {
     f.render_widget(status_line, chunks[0]);
 
     // bottom keymap legend
+    let keys = model.config.keys;
+    let key_labels = [
+        keys.set_quality.to_string(),
+        keys.rerender.to_string(),
+        keys.move_render.to_string(),
+        keys.file_picker.to_string(),
+        keys.pick_scene.to_string(),
+        keys.process_list.to_string(),
+        keys.toggle_source.to_string(),
+        keys.pick_bookmark.to_string(),
+        format!("{} / {}", keys.history_prev, keys.history_next),
+    ];
+
     let mut legend = vec![
         legend::Element::new("q", "quit"),
         legend::Element::new("space", "begin chord"),
@@ -429,15 +813,22 @@ fn main_ui(f: &mut Frame, screen: &Screen, model: &mut Model) {
 
     if model.prev_was_space.is_some() {
         legend = vec![
-            legend::Element::new("q", "set quality"),
-            legend::Element::new("r", "render last file"),
-            legend::Element::new("m", "move last render to work directory"),
-            legend::Element::new("f", "change working directory"),
+            legend::Element::new(&key_labels[0], "set quality"),
+            legend::Element::new(&key_labels[1], "render last file"),
+            legend::Element::new(&key_labels[2], "move last render to work directory"),
+            legend::Element::new(&key_labels[3], "change working directory"),
+            legend::Element::new(&key_labels[4], "pick scene"),
+            legend::Element::new(&key_labels[5], "view running/queued jobs"),
+            legend::Element::new(&key_labels[6], "toggle source pane"),
+            legend::Element::new(&key_labels[7], "jump to bookmark"),
         ];
+    } else {
+        legend.push(legend::Element::new(&key_labels[8], "previous/next render"));
     }
     if let Some(key) = model.chord {
         match key {
-            Chord::FilePicker | Chord::Move | Chord::ReRender => {}
+            Chord::FilePicker | Chord::Move | Chord::ReRender | Chord::PickScene => {}
+            Chord::ProcessList | Chord::ToggleSource | Chord::PickBookmark => {}
             Chord::SetQuality => {
                 legend = vec![
                     legend::Element::new("l", "480p"),
@@ -452,7 +843,172 @@ fn main_ui(f: &mut Frame, screen: &Screen, model: &mut Model) {
     f.render_widget(legend.iter().render(), chunks[2]);
 }
 
-fn ui(f: &mut Frame, screen: &Screen, model: &mut Model) {
+/// Compact scrollback index: one line per run with its source file, quality
+/// symbol, exit code and time since it started, the selected entry highlighted.
+fn render_history_list(f: &mut Frame, area: ratatui::layout::Rect, model: &Model) {
+    let block = Block::default().borders(Borders::ALL).title(" runs ");
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    let selected = model.history.selected_index();
+    let lines: Vec<Line> = model
+        .history
+        .entries()
+        .iter()
+        .enumerate()
+        .map(|(i, entry)| {
+            let status = match *entry.exit_status.lock().unwrap() {
+                Some(0) => Span::styled("ok", Style::new().fg(Color::Green)),
+                Some(code) => Span::styled(format!("{code}"), Style::new().fg(Color::Red)),
+                None => Span::styled("...", Style::new().fg(Color::Yellow)),
+            };
+            let elapsed = format!("{:.0}s", entry.started_at.elapsed().as_secs_f32());
+            let file = truncate_string_to(
+                &entry.source_file,
+                (inner.width as usize).max(8).saturating_sub(6 + elapsed.len()),
+            );
+            let style = if i == selected {
+                Style::new().on_dark_gray()
+            } else {
+                Style::new()
+            };
+            Line::from(vec![
+                Span::styled(format!("{} ", entry.quality_symbol), style),
+                Span::styled(file, style),
+                Span::raw(" "),
+                status,
+                Span::raw(" "),
+                Span::styled(elapsed, Style::new().fg(Color::DarkGray)),
+            ])
+        })
+        .collect();
+    f.render_widget(Paragraph::new(lines), inner);
+}
+
+fn ui(f: &mut Frame, screen: Option<&Screen>, model: &mut Model) {
+    if let Some(selected) = model.process_list {
+        let chunks = ratatui::layout::Layout::default()
+            .direction(ratatui::layout::Direction::Vertical)
+            .margin(1)
+            .constraints([
+                Constraint::Min(2),
+                Constraint::Percentage(100),
+                Constraint::Min(1),
+            ])
+            .split(f.size());
+
+        let header = "queued, running and recently finished renders";
+        let header = Paragraph::new(header).style(Style::new().italic());
+
+        let legend = vec![
+            legend::Element::new("q/esc", "close"),
+            legend::Element::new("j/k", "navigate"),
+            legend::Element::new("x", "kill selected job"),
+        ];
+
+        let queue = model.jobs.lock().unwrap();
+        let items: Vec<ratatui::widgets::ListItem> = queue
+            .jobs()
+            .iter()
+            .map(|job| {
+                let state = match job.state {
+                    jobs::JobState::Queued => "queued".to_string(),
+                    jobs::JobState::Running => "running".to_string(),
+                    jobs::JobState::Done { exit_code } => format!("done ({exit_code})"),
+                    jobs::JobState::Failed => "failed".to_string(),
+                };
+                ratatui::widgets::ListItem::new(format!(
+                    "#{} {} [{}] {} — {:.1}s",
+                    job.id,
+                    job.source_file,
+                    job.quality_symbol,
+                    state,
+                    job.elapsed().as_secs_f32()
+                ))
+            })
+            .collect();
+        let mut state = ratatui::widgets::ListState::default();
+        state.select(Some(selected));
+        let list = ratatui::widgets::List::new(items)
+            .block(Block::default().borders(Borders::ALL).title(" jobs "))
+            .highlight_style(Style::new().on_dark_gray());
+        drop(queue);
+
+        f.render_widget(header, chunks[0]);
+        f.render_stateful_widget(list, chunks[1], &mut state);
+        f.render_widget(legend.iter().render(), chunks[2]);
+        return;
+    }
+    if let Some(picker) = model.scene_picker.as_mut() {
+        let chunks = ratatui::layout::Layout::default()
+            .direction(ratatui::layout::Direction::Vertical)
+            .margin(1)
+            .constraints([
+                Constraint::Min(2),
+                Constraint::Percentage(100),
+                Constraint::Min(1),
+            ])
+            .split(f.size());
+
+        let header = "pick a scene to render";
+        let header = Paragraph::new(header).style(Style::new().italic());
+
+        let legend = vec![
+            legend::Element::new("q", "quit"),
+            legend::Element::new("j/k", "navigate"),
+            legend::Element::new("space/enter", "confirm"),
+            legend::Element::new("esc", "cancel"),
+        ];
+
+        let items: Vec<ratatui::widgets::ListItem> = picker
+            .scenes()
+            .iter()
+            .map(|s| ratatui::widgets::ListItem::new(s.as_str()))
+            .collect();
+        let list = ratatui::widgets::List::new(items)
+            .block(Block::default().borders(Borders::ALL).title(" scenes "))
+            .highlight_style(Style::new().on_dark_gray());
+
+        f.render_widget(header, chunks[0]);
+        f.render_stateful_widget(list, chunks[1], picker.state());
+        f.render_widget(legend.iter().render(), chunks[2]);
+        return;
+    }
+    if let Some(picker) = model.bookmark_picker.as_mut() {
+        let chunks = ratatui::layout::Layout::default()
+            .direction(ratatui::layout::Direction::Vertical)
+            .margin(1)
+            .constraints([
+                Constraint::Min(2),
+                Constraint::Percentage(100),
+                Constraint::Min(1),
+            ])
+            .split(f.size());
+
+        let header = "jump to a bookmarked directory";
+        let header = Paragraph::new(header).style(Style::new().italic());
+
+        let legend = vec![
+            legend::Element::new("q", "quit"),
+            legend::Element::new("j/k", "navigate"),
+            legend::Element::new("space/enter", "confirm"),
+            legend::Element::new("esc", "cancel"),
+        ];
+
+        let items: Vec<ratatui::widgets::ListItem> = picker
+            .bookmarks()
+            .iter()
+            .map(|b| ratatui::widgets::ListItem::new(format!("{} ({})", b.name, b.path.display())))
+            .collect();
+        let list = ratatui::widgets::List::new(items)
+            .block(Block::default().borders(Borders::ALL).title(" bookmarks "))
+            .highlight_style(Style::new().on_dark_gray());
+
+        f.render_widget(header, chunks[0]);
+        f.render_stateful_widget(list, chunks[1], picker.state());
+        f.render_widget(legend.iter().render(), chunks[2]);
+        return;
+    }
     if let Some(fp) = model.file_picker.as_mut() {
         let chunks = ratatui::layout::Layout::default()
             .direction(ratatui::layout::Direction::Vertical)
@@ -467,10 +1023,12 @@ fn ui(f: &mut Frame, screen: &Screen, model: &mut Model) {
         let header = "choose a directory to monitor for file changes";
         let header = Paragraph::new(header).style(Style::new().italic());
 
+        let bookmark_add_label = model.config.keys.bookmark_add.to_string();
         let legend = vec![
             legend::Element::new("q", "quit"),
             legend::Element::new("hjkl / ←↓↑→", "navigate"),
             legend::Element::new("space", "confirm"),
+            legend::Element::new(&bookmark_add_label, "bookmark this directory"),
             legend::Element::new("esc", "cancel"),
         ];
         let size_ref = model.size.cols.saturating_sub(4);
@@ -489,7 +1047,11 @@ fn ui(f: &mut Frame, screen: &Screen, model: &mut Model) {
     main_ui(f, screen, model)
 }
 
-fn setup_terminal() -> io::Result<(Terminal<CrosstermBackend<BufWriter<io::Stdout>>>, Size)> {
+fn setup_terminal() -> io::Result<(
+    Terminal<CrosstermBackend<BufWriter<io::Stdout>>>,
+    Size,
+    GraphicsProtocol,
+)> {
     enable_raw_mode()?;
     let stdout = io::stdout();
     let backend = CrosstermBackend::new(BufWriter::new(stdout));
@@ -499,8 +1061,9 @@ fn setup_terminal() -> io::Result<(Terminal<CrosstermBackend<BufWriter<io::Stdou
         rows: initial_size.height,
         cols: initial_size.width,
     };
+    let graphics_protocol = preview::detect_terminal_capability();
     execute!(terminal.backend_mut(), EnterAlternateScreen)?;
-    Ok((terminal, size))
+    Ok((terminal, size, graphics_protocol))
 }
 
 fn cleanup_terminal(
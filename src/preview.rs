@@ -0,0 +1,267 @@
+use anyhow::{bail, Result};
+use ratatui::style::Color;
+use ratatui::text::{Line, Span};
+use std::path::Path;
+use std::process::Command;
+
+/// Which inline-image transport the attached terminal understands, probed once
+/// in `setup_terminal` from environment variables a terminal sets about itself.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GraphicsProtocol {
+    Kitty,
+    Sixel,
+    None,
+}
+
+pub fn detect_terminal_capability() -> GraphicsProtocol {
+    if std::env::var("KITTY_WINDOW_ID").is_ok()
+        || std::env::var("TERM").is_ok_and(|t| t.contains("kitty"))
+        || std::env::var("TERM_PROGRAM").is_ok_and(|t| t == "WezTerm" || t == "ghostty")
+    {
+        return GraphicsProtocol::Kitty;
+    }
+    if std::env::var("TERM").is_ok_and(|t| t.contains("sixel"))
+        || std::env::var("COLORTERM").is_ok_and(|t| t.contains("sixel"))
+    {
+        return GraphicsProtocol::Sixel;
+    }
+    GraphicsProtocol::None
+}
+
+/// The most recently rendered file decoded to raw RGBA, plus the PNG bytes
+/// needed by the Kitty transport (which wants a re-encodable image, not pixels).
+pub struct Frame {
+    pub width: u32,
+    pub height: u32,
+    pub rgba: Vec<u8>,
+    pub png: Vec<u8>,
+}
+
+/// Load `rendered_to` as a preview frame: decode a `.png` directly, or pull the
+/// first frame out of a `.mp4`/`.mov` with an `ffmpeg` subprocess.
+pub fn load_frame(rendered_to: &str) -> Result<Frame> {
+    let path = Path::new(rendered_to);
+    let png = match path.extension().and_then(|e| e.to_str()) {
+        Some("png") => std::fs::read(path)?,
+        Some("mp4") | Some("mov") => extract_first_frame(path)?,
+        other => bail!("don't know how to preview {other:?} output"),
+    };
+    let image = image::load_from_memory(&png)?.to_rgba8();
+    Ok(Frame {
+        width: image.width(),
+        height: image.height(),
+        rgba: image.into_raw(),
+        png,
+    })
+}
+
+/// Decoded frame cache keyed on the rendered output's path and modified time,
+/// so a frame is only decoded — and its Kitty/sixel payload only (re)encoded —
+/// when manim produces a new render, not on every redraw of the main loop.
+#[derive(Default)]
+pub struct PreviewCache {
+    source: Option<String>,
+    source_mtime: Option<std::time::SystemTime>,
+    frame: Option<Frame>,
+    payload: Option<String>,
+    dirty: bool,
+}
+
+impl PreviewCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Re-decode `rendered_to` and re-encode its protocol payload (sized to
+    /// `target_cols`/`target_rows`) only if its path or modified time differs
+    /// from what's already cached.
+    pub fn refresh(
+        &mut self,
+        rendered_to: &str,
+        protocol: GraphicsProtocol,
+        target_cols: u16,
+        target_rows: u16,
+    ) {
+        let mtime = std::fs::metadata(rendered_to)
+            .and_then(|m| m.modified())
+            .ok();
+        if self.source.as_deref() == Some(rendered_to) && self.source_mtime == mtime {
+            return;
+        }
+        let Ok(frame) = load_frame(rendered_to) else {
+            return;
+        };
+        self.payload = match protocol {
+            GraphicsProtocol::Kitty => Some(encode_kitty(&frame)),
+            GraphicsProtocol::Sixel => Some(encode_sixel(&frame, target_cols, target_rows)),
+            GraphicsProtocol::None => None,
+        };
+        self.frame = Some(frame);
+        self.source = Some(rendered_to.to_owned());
+        self.source_mtime = mtime;
+        self.dirty = true;
+    }
+
+    pub fn frame(&self) -> Option<&Frame> {
+        self.frame.as_ref()
+    }
+
+    pub fn payload(&self) -> Option<&str> {
+        self.payload.as_deref()
+    }
+
+    /// True exactly once after a new frame is decoded — consuming it tells
+    /// the caller whether the payload actually needs retransmitting, instead
+    /// of blitting the same escapes to the terminal on every redraw.
+    pub fn take_dirty(&mut self) -> bool {
+        std::mem::take(&mut self.dirty)
+    }
+}
+
+fn extract_first_frame(path: &Path) -> Result<Vec<u8>> {
+    let output = Command::new("ffmpeg")
+        .args(["-y", "-i"])
+        .arg(path)
+        .args(["-frames:v", "1", "-f", "image2pipe", "-vcodec", "png", "-"])
+        .output()?;
+    if !output.status.success() {
+        bail!(
+            "ffmpeg failed to pull a frame from {}: {}",
+            path.display(),
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+    Ok(output.stdout)
+}
+
+/// Fixed Kitty image id reused for every preview frame, so a new transmission
+/// with `a=T` replaces the previous placement instead of piling up a fresh
+/// image (and terminal-side memory) on every redraw.
+const KITTY_IMAGE_ID: u32 = 1;
+
+/// Chunked Kitty graphics protocol payload for `frame`, not yet positioned —
+/// the caller prepends a cursor-move escape. Each chunk is capped at 4096
+/// base64 bytes as the spec requires.
+pub fn encode_kitty(frame: &Frame) -> String {
+    use base64::{engine::general_purpose::STANDARD, Engine};
+
+    let encoded = STANDARD.encode(&frame.png);
+    let chunks: Vec<&[u8]> = encoded.as_bytes().chunks(4096).collect();
+
+    let mut out = String::new();
+    for (i, chunk) in chunks.iter().enumerate() {
+        let more = u8::from(i + 1 != chunks.len());
+        let control = if i == 0 {
+            format!("f=100,a=T,i={KITTY_IMAGE_ID},m={more}")
+        } else {
+            format!("m={more}")
+        };
+        out.push_str("\x1b_G");
+        out.push_str(&control);
+        out.push(';');
+        out.push_str(std::str::from_utf8(chunk).expect("base64 is ascii"));
+        out.push_str("\x1b\\");
+    }
+    out
+}
+
+/// Quantize `frame` to a 16-levels-per-channel palette and build a sixel
+/// payload, not yet positioned — the caller prepends a cursor-move escape.
+/// `target_cols`/`target_rows` are the preview pane's size in cells: the
+/// frame is first decimated to at most one pixel per cell column and six
+/// pixels (one sixel band) per cell row, since sixel
+/// cost (both encode time and payload size) scales with pixel count, not
+/// with how big the source render happens to be.
+pub fn encode_sixel(frame: &Frame, target_cols: u16, target_rows: u16) -> String {
+    use std::fmt::Write as _;
+
+    const LEVELS: u32 = 16;
+    let palette_of = |r: u8, g: u8, b: u8| -> u32 {
+        let q = |c: u8| (c as u32 * (LEVELS - 1)) / 255;
+        q(r) * LEVELS * LEVELS + q(g) * LEVELS + q(b)
+    };
+
+    let width = (target_cols.max(1) as u32).min(frame.width);
+    let height = ((target_rows.max(1) as u32) * 6).min(frame.height);
+    let color_at = |x: u32, y: u32| -> u32 {
+        let sx = (x * frame.width / width).min(frame.width - 1);
+        let sy = (y * frame.height / height).min(frame.height - 1);
+        let o = ((sy * frame.width + sx) * 4) as usize;
+        palette_of(frame.rgba[o], frame.rgba[o + 1], frame.rgba[o + 2])
+    };
+
+    let mut out = String::from("\x1bPq");
+    for i in 0..LEVELS {
+        for j in 0..LEVELS {
+            for k in 0..LEVELS {
+                let idx = i * LEVELS * LEVELS + j * LEVELS + k;
+                let pct = |level: u32| level * 100 / (LEVELS - 1);
+                let _ = write!(out, "#{idx};2;{};{};{}", pct(i), pct(j), pct(k));
+            }
+        }
+    }
+
+    for band in (0..height).step_by(6) {
+        for y_bit in 0..6u32 {
+            let y = band + y_bit;
+            if y >= height {
+                break;
+            }
+            let ch = (0x3f + (1 << y_bit)) as u8 as char;
+            // Coalesce runs of same-colored pixels into a single color select
+            // plus a sixel repeat introducer, instead of one allocation and
+            // one color select per pixel.
+            let mut x = 0;
+            while x < width {
+                let idx = color_at(x, y);
+                let mut run = 1;
+                while x + run < width && color_at(x + run, y) == idx {
+                    run += 1;
+                }
+                let _ = write!(out, "#{idx}");
+                if run > 1 {
+                    let _ = write!(out, "!{run}{ch}");
+                } else {
+                    out.push(ch);
+                }
+                x += run;
+            }
+            // Return to the start of this band so the next bit-plane overlays
+            // the same columns instead of smearing past the right edge.
+            out.push('$');
+        }
+        out.push('-');
+    }
+    out.push_str("\x1b\\");
+    out
+}
+
+/// Render `frame` as ratatui `Line`s of half-block glyphs (two source pixels per
+/// cell, foreground = top pixel, background = bottom pixel) for terminals with
+/// no inline image support at all.
+pub fn render_halfblocks(frame: &Frame, target_cols: u16, target_rows: u16) -> Vec<Line<'static>> {
+    let cols = target_cols.max(1) as u32;
+    let rows = (target_rows.max(1) as u32) * 2;
+
+    let mut lines = Vec::with_capacity(rows as usize / 2);
+    for row in (0..rows).step_by(2) {
+        let mut spans = Vec::with_capacity(cols as usize);
+        for col in 0..cols {
+            let top = sample(frame, col, row, cols, rows);
+            let bottom = sample(frame, col, row + 1, cols, rows);
+            spans.push(Span::styled(
+                "\u{2580}",
+                ratatui::style::Style::new().fg(top).bg(bottom),
+            ));
+        }
+        lines.push(Line::from(spans));
+    }
+    lines
+}
+
+fn sample(frame: &Frame, col: u32, row: u32, cols: u32, rows: u32) -> Color {
+    let x = (col * frame.width / cols).min(frame.width - 1);
+    let y = (row * frame.height / rows).min(frame.height - 1);
+    let o = ((y * frame.width + x) * 4) as usize;
+    Color::Rgb(frame.rgba[o], frame.rgba[o + 1], frame.rgba[o + 2])
+}
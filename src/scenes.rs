@@ -0,0 +1,62 @@
+use ratatui::widgets::ListState;
+
+/// Extract the names of every `Scene` subclass defined in a manim source file,
+/// so multi-scene files can be pointed at a single class instead of forcing
+/// manim to prompt on a pipe it can't read.
+///
+/// Matches lines like `class Foo(Scene):` or `class Foo(ThreeDScene, MovingCameraScene):`,
+/// keeping any class whose base list has at least one identifier ending in `Scene`.
+pub fn parse_scenes(source: &str) -> Vec<String> {
+    source
+        .lines()
+        .filter_map(|line| {
+            let rest = line.trim_start().strip_prefix("class ")?;
+            let (name, bases) = rest.split_once('(')?;
+            let bases = bases.split(')').next()?;
+            bases
+                .split(',')
+                .any(|base| base.trim().ends_with("Scene"))
+                .then(|| name.trim().to_owned())
+        })
+        .collect()
+}
+
+/// `FileExplorer`-style selectable overlay listing the scenes found in the
+/// watched file.
+pub struct ScenePicker {
+    scenes: Vec<String>,
+    state: ListState,
+}
+
+impl ScenePicker {
+    pub fn new(scenes: Vec<String>) -> Self {
+        let mut state = ListState::default();
+        state.select(Some(0));
+        Self { scenes, state }
+    }
+
+    pub fn scenes(&self) -> &[String] {
+        &self.scenes
+    }
+
+    pub fn state(&mut self) -> &mut ListState {
+        &mut self.state
+    }
+
+    pub fn select_prev(&mut self) {
+        let i = self.state.selected().unwrap_or(0).saturating_sub(1);
+        self.state.select(Some(i));
+    }
+
+    pub fn select_next(&mut self) {
+        let i = (self.state.selected().unwrap_or(0) + 1).min(self.scenes.len().saturating_sub(1));
+        self.state.select(Some(i));
+    }
+
+    pub fn selected(&self) -> Option<&str> {
+        self.state
+            .selected()
+            .and_then(|i| self.scenes.get(i))
+            .map(String::as_str)
+    }
+}